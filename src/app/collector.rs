@@ -0,0 +1,108 @@
+use std::ops::Range;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use sysinfo::{Pid, ProcessRefreshKind, RefreshKind, Signal, UpdateKind};
+
+use crate::app::matcher::Matcher;
+
+/// A single process's resource usage, as observed at one point in sampling time.
+pub struct ProcessSample {
+    pub pid: u32,
+    /// The string the process was matched against (its name, path, or command line,
+    /// depending on the configured `MatchTarget`), and the span of the match within it.
+    pub matched: String,
+    pub match_span: Range<usize>,
+    pub run_time: u64,
+    pub memory: u64,
+    pub cpu_usage: f32,
+    pub total_read_bytes: u64,
+    pub total_written_bytes: u64,
+}
+
+/// A message sent from the collector thread to the main loop.
+pub enum Update {
+    /// A fresh snapshot of all processes matching the query, taken at the collector's own tick.
+    Snapshot(Vec<ProcessSample>),
+}
+
+/// A command sent from the main loop to the collector thread.
+pub enum Command {
+    /// Terminate the process with the given pid. A graceful `SIGTERM` is attempted first,
+    /// falling back to a forceful kill if that is not supported on this platform.
+    Kill(u32),
+}
+
+/// How often to check for a pending `Command` while waiting out the sample interval, so a kill
+/// request doesn't sit queued for up to a whole interval before it is acted on.
+const COMMAND_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Spawn a background thread that owns the `sysinfo::System`, refreshes it on a fixed
+/// `interval`, and sends a [`Update::Snapshot`] of the processes matching `matcher` over the
+/// returned channel. The returned `Sender` lets the caller issue [`Command`]s, such as killing
+/// a process, back to the thread that owns the system.
+///
+/// Keeping the system on its own thread with a steady tick decouples the sample cadence from
+/// however fast (or slow) the main thread happens to be rendering and handling input, so mashing
+/// keys no longer corrupts the CPU sampling.
+pub fn spawn(matcher: Matcher, interval: Duration) -> (Receiver<Update>, Sender<Command>) {
+    let (update_tx, update_rx) = mpsc::channel();
+    let (command_tx, command_rx) = mpsc::channel();
+
+    thread::spawn(move || {
+        let refreshes = RefreshKind::nothing().with_processes(
+            ProcessRefreshKind::nothing()
+                .with_memory()
+                .with_cpu()
+                .with_disk_usage()
+                .with_cmd(UpdateKind::Always)
+                .with_exe(UpdateKind::Always),
+        );
+        let mut sys = sysinfo::System::new_with_specifics(refreshes);
+
+        loop {
+            sys.refresh_specifics(refreshes);
+
+            let snapshot = sys
+                .processes()
+                .values()
+                .filter_map(|process| {
+                    let matched = matcher.matches(process)?;
+                    Some(ProcessSample {
+                        pid: process.pid().as_u32(),
+                        matched: matched.haystack,
+                        match_span: matched.span,
+                        run_time: process.run_time(),
+                        memory: process.memory(),
+                        cpu_usage: process.cpu_usage(),
+                        total_read_bytes: process.disk_usage().total_read_bytes,
+                        total_written_bytes: process.disk_usage().total_written_bytes,
+                    })
+                })
+                .collect();
+
+            // If the main thread is gone, there is nobody left to send updates to.
+            if update_tx.send(Update::Snapshot(snapshot)).is_err() {
+                break;
+            }
+
+            let tick = Instant::now();
+            while tick.elapsed() < interval {
+                match command_rx.recv_timeout(COMMAND_POLL_INTERVAL) {
+                    Ok(Command::Kill(pid)) => {
+                        if let Some(process) = sys.process(Pid::from_u32(pid))
+                            && process.kill_with(Signal::Term).is_none()
+                        {
+                            process.kill();
+                        }
+                    }
+                    Err(RecvTimeoutError::Timeout) => {}
+                    Err(RecvTimeoutError::Disconnected) => return,
+                }
+            }
+        }
+    });
+
+    (update_rx, command_tx)
+}
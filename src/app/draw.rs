@@ -0,0 +1,283 @@
+use chrono::Local;
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Modifier, Stylize};
+use ratatui::symbols::Marker;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Axis, Chart, Dataset, GraphType, Paragraph, Widget};
+use size::Size;
+
+use crate::app::entry::{Entry, EntryLayout, EntryState};
+
+mod colors {
+    use ratatui::style::Color;
+
+    pub const INFO: Color = Color::from_u32(0x808a9f);
+    pub const INFO_NAME: Color = Color::from_u32(0xd29dc0);
+    pub const INFO_MATCH: Color = Color::from_u32(0xff5cb0);
+    pub const MEM: Color = Color::from_u32(0xe280c1);
+    pub const CPU: Color = Color::from_u32(0xbad29f);
+    pub const DISK_READ: Color = Color::from_u32(0x8fa7e0);
+    pub const DISK_WRITE: Color = Color::from_u32(0xf6ab65);
+}
+
+/// If the series starts after `x_min`, leaving a gap at the chart's left edge (e.g. right after
+/// a process is discovered, or once old samples have been evicted), synthesize a boundary point
+/// at `x_min` and prepend it, so every chart spans the full axis cleanly. The earliest value is
+/// clamped out to the edge horizontally, rather than extrapolating the slope between the first
+/// two samples: for a freshly-discovered entry those samples can be a single collector tick
+/// apart while `x_min` is up to an entire retention window away, so extrapolating would blow up
+/// an ordinary delta between them into a huge, often nonsensical synthetic value.
+fn pad_left_edge(data: &[(f64, f64)], x_min: f64) -> Vec<(f64, f64)> {
+    match data {
+        [] => Vec::new(),
+        [(x0, _), ..] if *x0 <= x_min => data.to_vec(),
+        [(_, y0), ..] => {
+            let mut padded = Vec::with_capacity(data.len() + 1);
+            padded.push((x_min, *y0));
+            padded.extend_from_slice(data);
+            padded
+        }
+    }
+}
+
+/// Renders a single [`Entry`] as one compact line — name, pid, current mem, cpu%, and
+/// read/write rates — for `--basic` mode, which drops the charts entirely.
+pub struct BasicRowWidget<'a> {
+    pub entry: &'a Entry,
+    pub selected: bool,
+}
+
+impl Widget for BasicRowWidget<'_> {
+    fn render(self, area: ratatui::prelude::Rect, buf: &mut ratatui::prelude::Buffer) {
+        let Self { entry, selected } = self;
+
+        let wilted = if entry.is_dead() { Modifier::DIM } else { Modifier::default() };
+        let modifier = if selected { wilted | Modifier::REVERSED } else { wilted };
+
+        let mem = entry.mem.last().copied().unwrap_or_default();
+        let cpu = entry.cpu.last().copied().unwrap_or_default();
+        let read = entry.read.last().copied().unwrap_or_default();
+        let write = entry.write.last().copied().unwrap_or_default();
+
+        let [before, matched, after] = entry.name_match();
+        let line = Line::from(vec![
+            Span::raw(before).dim(),
+            Span::raw(matched).bold().fg(colors::INFO_MATCH),
+            Span::raw(after).dim(),
+            Span::raw(format!(" [{}]", entry.pid)).fg(colors::INFO).dim(),
+            Span::raw("  mem ").fg(colors::MEM),
+            Span::raw(mem.format().to_string()),
+            Span::raw("  cpu ").fg(colors::CPU),
+            Span::raw(format!("{cpu:>5.2}")),
+            Span::raw("  read ").fg(colors::DISK_READ),
+            Span::raw(format!("{}/s", read.format())),
+            Span::raw("  wrote ").fg(colors::DISK_WRITE),
+            Span::raw(format!("{}/s", write.format())),
+        ]);
+        Paragraph::new(line).add_modifier(modifier).render(area, buf);
+    }
+}
+
+/// Wraps an [`Entry`] with whether it is currently the selected entry, so the widget can
+/// render a highlighted header for it.
+pub struct EntryWidget<'a> {
+    pub entry: &'a Entry,
+    pub selected: bool,
+}
+
+impl Widget for EntryWidget<'_> {
+    fn render(self, area: ratatui::prelude::Rect, buf: &mut ratatui::prelude::Buffer) {
+        let Self { entry, selected } = self;
+
+        let layout = Layout::vertical([
+            Constraint::Length(1),                          // Entry header.
+            Constraint::Length(entry.layout.chart_height()), // Info, charts.
+        ]);
+
+        let entry_layout = Layout::horizontal([
+            Constraint::Length(22), // Info.
+            Constraint::Fill(1),    // Memory.
+            Constraint::Fill(1),    // CPU.
+            Constraint::Fill(1),    // Disk I/O.
+        ])
+        .spacing(1);
+        let [info_area, mem_area, cpu_area, disk_area] = entry_layout.areas(area);
+
+        // If a process is dead, we want to dim some of its colors.
+        let wilted = if entry.is_dead() { Modifier::DIM } else { Modifier::default() };
+
+        // The x-axis of every chart spans the retention window, in seconds relative to now, so
+        // the horizontal scale is stable and comparable across entries regardless of how many
+        // samples happen to be retained.
+        let now = Local::now().naive_local();
+        let x_min = -entry.mem.retention().as_secs_f64();
+        let x_max = 0.0;
+        let elapsed = |time: chrono::NaiveDateTime| (time - now).num_milliseconds() as f64 / 1000.0;
+
+        // General information about the process.
+        {
+            let duration = match entry.state {
+                EntryState::Alive => Local::now().naive_local().signed_duration_since(entry.start),
+                EntryState::Dead(time_of_death) => time_of_death.signed_duration_since(entry.start),
+            };
+            let start_time = match duration.num_days() {
+                ..=0 => entry.start.format("%H:%M").to_string(),
+                _ => entry.start.format("%a %b %d %H:%M").to_string(),
+            };
+            let duration = {
+                let days = duration.num_days();
+                let hours = duration.num_hours() % 24;
+                let minutes = duration.num_minutes() % 60;
+                let seconds = duration.num_seconds() % 60;
+                match (days, hours, minutes, seconds) {
+                    (0, 0, 0, s) => format!("{s}s"),
+                    (0, 0, m, s) => format!("{m}m{s:02}s"),
+                    (0, h, m, s) => format!("{h}h{m:02}m{s:02}s"),
+                    (d, h, m, s) => format!("{d}d{h:02}h{m:02}m{s:02}s"),
+                }
+            };
+
+            let [before, matched, after] = entry.name_match();
+            let name = Line::from(vec![
+                Span::raw(before).dim(),
+                Span::raw(matched).bold().fg(colors::INFO_MATCH),
+                Span::raw(after).dim(),
+            ])
+            .fg(colors::INFO_NAME);
+            let pid = Span::from(entry.pid.to_string()).italic().fg(colors::INFO).dim();
+            let start_time = Span::from(start_time).fg(colors::INFO).dim();
+            let duration = Span::from(duration).fg(colors::INFO);
+            let info = match entry.layout {
+                EntryLayout::Expanded => Paragraph::new(vec![
+                    name,
+                    Line::from(pid).right_aligned(),
+                    Line::from(start_time).right_aligned(),
+                    Line::from(duration).right_aligned(),
+                ]),
+                EntryLayout::Condensed => {
+                    let mut top = name;
+                    top.push_span(Span::raw(" "));
+                    top.push_span(pid);
+                    Paragraph::new(vec![
+                        top,
+                        Line::from(vec![duration, Span::raw(" "), start_time]).right_aligned(),
+                    ])
+                }
+            };
+            let header_modifier =
+                if selected { wilted | Modifier::REVERSED } else { wilted };
+            info.add_modifier(header_modifier).render(info_area, buf);
+        }
+
+        // Memory usage.
+        {
+            let current = entry.mem.last().copied().unwrap_or_default();
+            let peak = entry.mem.iter().map(|(_, y)| *y).max().unwrap_or_default();
+            let header = Line::from(vec![
+                Span::from("mem ").fg(colors::MEM),
+                Span::from(current.format().to_string()),
+                Span::from("  peak ").dim(),
+                Span::from(peak.format().to_string()),
+            ]);
+
+            let data: Vec<_> = entry.mem.iter().map(|(t, y)| (elapsed(*t), y.bytes() as f64)).collect();
+            let data = pad_left_edge(&data, x_min);
+            let dataset = Dataset::default()
+                .data(&data)
+                .marker(Marker::Braille)
+                .graph_type(GraphType::Line)
+                .fg(colors::MEM);
+            let chart = Chart::new(vec![dataset])
+                .x_axis(Axis::default().bounds([x_min, x_max]))
+                .y_axis(Axis::default().bounds([0.0, peak.bytes() as f64]));
+
+            let [header_area, chart_area] = layout.areas(mem_area);
+            header.add_modifier(wilted).render(header_area, buf);
+            chart.add_modifier(wilted).render(chart_area, buf);
+        }
+
+        // CPU usage.
+        {
+            let current = entry.cpu.last().copied().unwrap_or_default();
+            let peak = entry.cpu.iter().map(|(_, y)| *y).max_by(f32::total_cmp).unwrap_or_default();
+            let header = Line::from(vec![
+                Span::from("cpu ").fg(colors::CPU),
+                Span::from(format!("{current:>5.2}")),
+                Span::from("  peak ").dim(),
+                Span::from(format!("{peak:>5.2}")),
+            ]);
+
+            let data: Vec<_> = entry.cpu.iter().map(|(t, y)| (elapsed(*t), *y as f64)).collect();
+            let data = pad_left_edge(&data, x_min);
+            let dataset = Dataset::default()
+                .data(&data)
+                .marker(Marker::Braille)
+                .graph_type(GraphType::Line)
+                .fg(colors::CPU);
+            let chart = Chart::new(vec![dataset])
+                .x_axis(Axis::default().bounds([x_min, x_max]))
+                .y_axis(Axis::default().bounds([0.0, peak as f64]));
+
+            let [header_area, chart_area] = layout.areas(cpu_area);
+            header.add_modifier(wilted).render(header_area, buf);
+            chart.add_modifier(wilted).render(chart_area, buf);
+        }
+
+        // Disk I/O.
+        {
+            let read_rate = entry.read.last().copied().unwrap_or_default();
+            let write_rate = entry.write.last().copied().unwrap_or_default();
+            let read_peak = entry.read.iter().map(|(_, y)| *y).max().unwrap_or_default();
+            let write_peak = entry.write.iter().map(|(_, y)| *y).max().unwrap_or_default();
+            let header = Line::from(vec![
+                Span::from("read ").fg(colors::DISK_READ),
+                Span::from(format!("{}/s", read_rate.format())),
+                Span::from(format!(" ({} total)", entry.read_total.format())).dim(),
+                Span::from("  wrote ").fg(colors::DISK_WRITE),
+                Span::from(format!("{}/s", write_rate.format())),
+                Span::from(format!(" ({} total)", entry.write_total.format())).dim(),
+            ]);
+
+            let read: Vec<_> = entry.read.iter().map(|(t, y)| (elapsed(*t), y.bytes() as f64)).collect();
+            let read = pad_left_edge(&read, x_min);
+            let write: Vec<_> = entry.write.iter().map(|(t, y)| (elapsed(*t), y.bytes() as f64)).collect();
+            let write = pad_left_edge(&write, x_min);
+            let read_dataset = Dataset::default()
+                .data(&read)
+                .marker(Marker::Braille)
+                .graph_type(GraphType::Line)
+                .fg(colors::DISK_READ);
+            let write_dataset = Dataset::default()
+                .data(&write)
+                .marker(Marker::Braille)
+                .graph_type(GraphType::Line)
+                .fg(colors::DISK_WRITE);
+
+            let [header_area, chart_area] = layout.areas(disk_area);
+            header.add_modifier(wilted).render(header_area, buf);
+            match entry.layout {
+                EntryLayout::Expanded => {
+                    let datasets = vec![read_dataset, write_dataset];
+                    let max = Size::max(read_peak, write_peak).bytes();
+                    Chart::new(datasets)
+                        .x_axis(Axis::default().bounds([x_min, x_max]))
+                        .y_axis(Axis::default().bounds([0.0, max as f64]))
+                        .add_modifier(wilted)
+                        .render(chart_area, buf)
+                }
+                EntryLayout::Condensed => {
+                    let read = Chart::new(vec![read_dataset])
+                        .x_axis(Axis::default().bounds([x_min, x_max]))
+                        .y_axis(Axis::default().bounds([0.0, read_peak.bytes() as f64]));
+                    let write = Chart::new(vec![write_dataset])
+                        .x_axis(Axis::default().bounds([x_min, x_max]))
+                        .y_axis(Axis::default().bounds([0.0, write_peak.bytes() as f64]));
+                    let two_charts_layout = Layout::horizontal(Constraint::from_fills([1, 1]));
+                    let [read_area, write_area] = two_charts_layout.areas(chart_area);
+                    read.add_modifier(wilted).render(read_area, buf);
+                    write.add_modifier(wilted).render(write_area, buf);
+                }
+            }
+        }
+    }
+}
@@ -1,45 +1,146 @@
+use std::collections::VecDeque;
+use std::ops::Range;
 use std::time::Duration;
 
-use chrono::Local;
+use chrono::{Local, NaiveDateTime};
 use size::Size;
-use sysinfo::Process;
+
+/// A bounded, time-ordered buffer of `(timestamp, value)` samples.
+///
+/// Samples older than the configured retention window are evicted as new ones arrive, so a
+/// long-running watch does not grow without bound, and the window of time a series spans stays
+/// comparable across entries regardless of how many samples happened to land in it.
+pub struct Series<T> {
+    retention: Duration,
+    samples: VecDeque<(NaiveDateTime, T)>,
+}
+
+impl<T> Series<T> {
+    pub fn new(retention: Duration) -> Self {
+        Self { retention, samples: VecDeque::new() }
+    }
+
+    pub fn push(&mut self, time: NaiveDateTime, value: T) {
+        self.samples.push_back((time, value));
+
+        let cutoff = time - self.retention;
+        while let Some(&(oldest, _)) = self.samples.front() {
+            if oldest < cutoff {
+                self.samples.pop_front();
+            } else {
+                break;
+            }
+        }
+    }
+
+    pub fn retention(&self) -> Duration {
+        self.retention
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &(NaiveDateTime, T)> {
+        self.samples.iter()
+    }
+
+    pub fn last(&self) -> Option<&T> {
+        self.samples.back().map(|(_, value)| value)
+    }
+}
 
 pub struct Entry {
     pub state: EntryState,
 
-    pub name: String,
+    /// The string this process was matched against (its name, path, or command line,
+    /// depending on the configured `MatchTarget`).
+    pub matched: String,
+    /// Byte span of the match within `matched`, used to highlight it in the entry header.
+    pub match_span: Range<usize>,
     pub pid: u32,
     pub start: chrono::NaiveDateTime,
-    pub query: String,
 
-    pub mem: Vec<Size>,
-    pub cpu: Vec<f32>,
-    pub read: Vec<Size>,
-    pub write: Vec<Size>,
+    pub mem: Series<Size>,
+    pub cpu: Series<f32>,
+    /// Read throughput, in bytes/sec.
+    pub read: Series<Size>,
+    /// Write throughput, in bytes/sec.
+    pub write: Series<Size>,
+    /// Lifetime cumulative bytes read, as last reported by `sysinfo`.
+    pub read_total: Size,
+    /// Lifetime cumulative bytes written, as last reported by `sysinfo`.
+    pub write_total: Size,
+    prev_disk_sample: Option<(chrono::NaiveDateTime, u64, u64)>,
     pub layout: EntryLayout,
 }
 
 impl Entry {
-    pub fn new(process: &Process, query: String) -> Self {
+    pub fn new(
+        pid: u32,
+        matched: String,
+        match_span: Range<usize>,
+        run_time: u64,
+        retention: Duration,
+    ) -> Self {
         Self {
             state: EntryState::Alive,
-            name: process.name().to_string_lossy().to_string(),
-            // TODO: Reconsider, bit weird but it works for what we want to do.
-            query,
-            pid: process.pid().as_u32(),
+            matched,
+            match_span,
+            pid,
             // TODO: The time stuff is a bit hastily implemented. Sit with it for a second.
-            start: Local::now().naive_local() - Duration::from_secs(process.run_time()),
-            mem: Default::default(),
-            cpu: Default::default(),
-            read: Default::default(),
-            write: Default::default(),
+            start: Local::now().naive_local() - Duration::from_secs(run_time),
+            mem: Series::new(retention),
+            cpu: Series::new(retention),
+            read: Series::new(retention),
+            write: Series::new(retention),
+            read_total: Size::from_bytes(0u64),
+            write_total: Size::from_bytes(0u64),
+            prev_disk_sample: None,
             layout: EntryLayout::Expanded,
         }
     }
 
+    /// Split `matched` into the parts before, within, and after the matched span, for
+    /// highlighting in the entry header.
     pub fn name_match(&self) -> [&str; 3] {
-        let (before, after) = self.name.split_once(&self.query).unwrap();
-        [before, &self.query, after]
+        let Range { start, end } = self.match_span;
+        [&self.matched[..start], &self.matched[start..end], &self.matched[end..]]
+    }
+
+    /// Record a disk usage sample, given the cumulative totals `sysinfo` reports. Rather than
+    /// storing those ever-growing totals directly, compute the throughput since the previous
+    /// sample (bytes/sec) and push that instead, so the charts show current activity rather
+    /// than a line that only ever ramps upward. The raw totals are kept in `read_total` and
+    /// `write_total` for display as a secondary, lifetime label.
+    pub fn push_disk_usage(
+        &mut self,
+        time: chrono::NaiveDateTime,
+        total_read_bytes: u64,
+        total_written_bytes: u64,
+    ) {
+        let rate = |total: u64, prev_total: u64, elapsed_secs: f64| -> u64 {
+            if elapsed_secs > 0.0 && total >= prev_total {
+                ((total - prev_total) as f64 / elapsed_secs) as u64
+            } else {
+                // No time has elapsed since the previous sample, or the counter went backwards
+                // (e.g. the process restarted and `sysinfo` reset its disk usage counters).
+                0
+            }
+        };
+
+        let (read_rate, write_rate) = match self.prev_disk_sample {
+            Some((prev_time, prev_read, prev_write)) => {
+                let elapsed_secs = (time - prev_time).num_milliseconds() as f64 / 1000.0;
+                (
+                    rate(total_read_bytes, prev_read, elapsed_secs),
+                    rate(total_written_bytes, prev_write, elapsed_secs),
+                )
+            }
+            None => (0, 0),
+        };
+
+        self.read.push(time, Size::from_bytes(read_rate));
+        self.write.push(time, Size::from_bytes(write_rate));
+        self.read_total = Size::from_bytes(total_read_bytes);
+        self.write_total = Size::from_bytes(total_written_bytes);
+        self.prev_disk_sample = Some((time, total_read_bytes, total_written_bytes));
     }
 
     pub fn die(&mut self) {
@@ -80,3 +181,88 @@ impl EntryLayout {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(secs: i64) -> chrono::NaiveDateTime {
+        chrono::DateTime::from_timestamp(secs, 0).unwrap().naive_utc()
+    }
+
+    fn entry() -> Entry {
+        Entry::new(1, "proc".to_string(), 0..4, 0, Duration::from_secs(60))
+    }
+
+    #[test]
+    fn series_retains_samples_within_the_window() {
+        let mut series = Series::new(Duration::from_secs(10));
+        series.push(time(0), 1);
+        series.push(time(5), 2);
+        series.push(time(10), 3);
+
+        assert_eq!(series.iter().map(|(_, v)| *v).collect::<Vec<_>>(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn series_retains_a_sample_exactly_at_the_cutoff() {
+        let mut series = Series::new(Duration::from_secs(10));
+        series.push(time(0), 1);
+        // Cutoff is `10 - 10 = 0`, i.e. exactly the first sample's timestamp, which should not
+        // be evicted (only samples strictly older than the cutoff are).
+        series.push(time(10), 2);
+
+        assert_eq!(series.iter().map(|(_, v)| *v).collect::<Vec<_>>(), vec![1, 2]);
+    }
+
+    #[test]
+    fn series_evicts_samples_older_than_retention_across_multiple_pushes() {
+        let mut series = Series::new(Duration::from_secs(10));
+        series.push(time(0), 1);
+        series.push(time(5), 2);
+        // Cutoff is now `11 - 10 = 1`, which evicts the sample at `t = 0` but keeps `t = 5`.
+        series.push(time(11), 3);
+
+        assert_eq!(series.iter().map(|(_, v)| *v).collect::<Vec<_>>(), vec![2, 3]);
+    }
+
+    #[test]
+    fn series_reports_its_configured_retention() {
+        let series: Series<i32> = Series::new(Duration::from_secs(42));
+        assert_eq!(series.retention(), Duration::from_secs(42));
+    }
+
+    #[test]
+    fn push_disk_usage_first_sample_reports_no_rate() {
+        let mut entry = entry();
+        entry.push_disk_usage(time(0), 1000, 2000);
+
+        assert_eq!(entry.read.last().copied(), Some(Size::from_bytes(0u64)));
+        assert_eq!(entry.write.last().copied(), Some(Size::from_bytes(0u64)));
+        assert_eq!(entry.read_total, Size::from_bytes(1000u64));
+        assert_eq!(entry.write_total, Size::from_bytes(2000u64));
+    }
+
+    #[test]
+    fn push_disk_usage_computes_rate_from_delta() {
+        let mut entry = entry();
+        entry.push_disk_usage(time(0), 1000, 2000);
+        entry.push_disk_usage(time(2), 3000, 2500);
+
+        assert_eq!(entry.read.last().copied(), Some(Size::from_bytes(1000u64))); // (3000-1000)/2s
+        assert_eq!(entry.write.last().copied(), Some(Size::from_bytes(250u64))); // (2500-2000)/2s
+    }
+
+    #[test]
+    fn push_disk_usage_treats_counter_reset_as_zero_rate() {
+        let mut entry = entry();
+        entry.push_disk_usage(time(0), 5000, 5000);
+        // The process restarted (or `sysinfo` otherwise reset its counters), so the totals drop.
+        entry.push_disk_usage(time(1), 100, 200);
+
+        assert_eq!(entry.read.last().copied(), Some(Size::from_bytes(0u64)));
+        assert_eq!(entry.write.last().copied(), Some(Size::from_bytes(0u64)));
+        assert_eq!(entry.read_total, Size::from_bytes(100u64));
+        assert_eq!(entry.write_total, Size::from_bytes(200u64));
+    }
+}
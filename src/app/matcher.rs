@@ -0,0 +1,147 @@
+use std::ops::Range;
+
+use anyhow::Context;
+use regex::Regex;
+use sysinfo::Process;
+
+/// Which part of a process to match the query against.
+#[derive(Clone, Copy)]
+pub enum MatchTarget {
+    /// The process's short name, e.g. `firefox`.
+    Name,
+    /// The process's full executable path.
+    Path,
+    /// The process's command-line arguments, joined with spaces.
+    Cmd,
+}
+
+enum Pattern {
+    Substring(String),
+    Regex(Regex),
+}
+
+/// A process was found to match; `haystack` is the string it was matched against (per
+/// `MatchTarget`) and `span` is the byte range of the match within it, for highlighting.
+pub struct Match {
+    pub haystack: String,
+    pub span: Range<usize>,
+}
+
+/// A query used to find processes of interest, and where in the process to look for it.
+pub struct Matcher {
+    target: MatchTarget,
+    pattern: Pattern,
+}
+
+impl Matcher {
+    pub fn new(query: String, target: MatchTarget, regex: bool) -> anyhow::Result<Self> {
+        let pattern = if regex {
+            Pattern::Regex(Regex::new(&query).context("invalid --regex pattern")?)
+        } else {
+            Pattern::Substring(query)
+        };
+
+        Ok(Self { target, pattern })
+    }
+
+    fn haystack(&self, process: &Process) -> String {
+        match self.target {
+            MatchTarget::Name => process.name().to_string_lossy().to_string(),
+            MatchTarget::Path => {
+                process.exe().map(|path| path.to_string_lossy().to_string()).unwrap_or_default()
+            }
+            MatchTarget::Cmd => process
+                .cmd()
+                .iter()
+                .map(|arg| arg.to_string_lossy())
+                .collect::<Vec<_>>()
+                .join(" "),
+        }
+    }
+
+    /// Test `haystack` against the pattern, returning the matched span if any. Split out from
+    /// [`Self::matches`] so the matching logic itself can be tested directly, independent of how
+    /// the haystack was obtained from a `Process`.
+    fn find(&self, haystack: &str) -> Option<Range<usize>> {
+        match &self.pattern {
+            Pattern::Substring(query) => {
+                haystack.find(query.as_str()).map(|start| start..start + query.len())
+            }
+            Pattern::Regex(regex) => regex.find(haystack).map(|m| m.range()),
+        }
+    }
+
+    /// Test whether `process` matches, returning the haystack and matched span for
+    /// highlighting if it does.
+    pub fn matches(&self, process: &Process) -> Option<Match> {
+        let haystack = self.haystack(process);
+        let span = self.find(&haystack)?;
+
+        Some(Match { haystack, span })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sysinfo::{Pid, ProcessRefreshKind, RefreshKind, UpdateKind};
+
+    use super::*;
+
+    #[test]
+    fn substring_match_finds_span() {
+        let matcher = Matcher::new("fox".to_string(), MatchTarget::Name, false).unwrap();
+        assert_eq!(matcher.find("firefox"), Some(4..7));
+    }
+
+    #[test]
+    fn substring_match_is_case_sensitive() {
+        let matcher = Matcher::new("Fox".to_string(), MatchTarget::Name, false).unwrap();
+        assert_eq!(matcher.find("firefox"), None);
+    }
+
+    #[test]
+    fn regex_match_finds_span() {
+        let matcher = Matcher::new(r"fire\w+".to_string(), MatchTarget::Cmd, true).unwrap();
+        assert_eq!(matcher.find("run firefox --flag"), Some(4..11));
+    }
+
+    #[test]
+    fn regex_match_respects_no_match() {
+        let matcher = Matcher::new(r"^chrome$".to_string(), MatchTarget::Name, true).unwrap();
+        assert_eq!(matcher.find("firefox"), None);
+    }
+
+    #[test]
+    fn invalid_regex_is_rejected() {
+        assert!(Matcher::new("(".to_string(), MatchTarget::Name, true).is_err());
+    }
+
+    #[test]
+    fn matches_against_real_process_covers_all_targets() {
+        let refreshes = RefreshKind::nothing().with_processes(
+            ProcessRefreshKind::nothing()
+                .with_cmd(UpdateKind::Always)
+                .with_exe(UpdateKind::Always),
+        );
+        let mut sys = sysinfo::System::new_with_specifics(refreshes);
+        sys.refresh_specifics(refreshes);
+        let process = sys
+            .process(Pid::from_u32(std::process::id()))
+            .expect("the current process should be visible to itself");
+
+        let name = process.name().to_string_lossy().to_string();
+        let matcher = Matcher::new(name.clone(), MatchTarget::Name, false).unwrap();
+        let matched = matcher.matches(process).expect("should match its own name");
+        assert_eq!(matched.haystack, name);
+
+        // `MatchTarget::Path` falls back to an empty haystack when `exe()` is `None`, so an
+        // empty query (which matches an empty haystack trivially) should still succeed.
+        let matcher = Matcher::new(String::new(), MatchTarget::Path, false).unwrap();
+        assert!(matcher.matches(process).is_some());
+
+        let cmd = process.cmd().iter().map(|arg| arg.to_string_lossy()).collect::<Vec<_>>().join(" ");
+        let matcher = Matcher::new(cmd.clone(), MatchTarget::Cmd, false).unwrap();
+        let matched = matcher.matches(process).expect("should match its own command line");
+        assert_eq!(matched.haystack, cmd);
+    }
+}
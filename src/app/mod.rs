@@ -1,4 +1,5 @@
 use std::collections::BTreeMap;
+use std::sync::mpsc::{Receiver, Sender};
 use std::time::Duration;
 
 use anyhow::Context;
@@ -6,37 +7,90 @@ use chrono::Local;
 use ratatui::crossterm::event::{self, Event, KeyCode, KeyModifiers};
 use ratatui::layout::{Constraint, Layout};
 use ratatui::prelude::Backend;
+use ratatui::style::Stylize;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::Paragraph;
 use ratatui::{Frame, Terminal};
 use size::Size;
-use sysinfo::{ProcessRefreshKind, RefreshKind};
 
 use crate::Config;
-use crate::app::entry::{Entry, EntryLayout, EntryState};
+use crate::app::collector::{Command, Update};
+use crate::app::draw::{BasicRowWidget, EntryWidget};
+use crate::app::entry::{Entry, EntryLayout};
+use crate::app::matcher::{MatchTarget, Matcher};
 
+mod collector;
 mod draw;
 mod entry;
+mod matcher;
+
+/// How often the collector thread refreshes system information and pushes a new sample.
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// How long to wait for terminal input before looping back around to redraw. This is
+/// independent of `SAMPLE_INTERVAL`, so input handling and redrawing can be as snappy as we
+/// like without affecting the sampling cadence.
+const INPUT_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// What the UI is currently doing, beyond just browsing entries.
+enum Mode {
+    Normal,
+    /// Waiting for the user to confirm killing the process with this pid.
+    ConfirmKill { pid: u32 },
+}
 
 pub struct Application {
     config: Config,
     is_running: bool,
+    mode: Mode,
+    /// Whether to render the compact `--basic` table instead of charts. Seeded from
+    /// `config.basic`, but toggleable at runtime with `B`.
+    basic: bool,
 
-    sys: sysinfo::System,
-    refreshes: RefreshKind,
+    updates: Receiver<Update>,
+    commands: Sender<Command>,
 
     // TODO: Actually, this is maybe a silly data structure, here, since new pid's should only
     // be appended, not inserted in between.
     entries: BTreeMap<u32, Entry>, // BTreeSet?
+
+    /// Index, within the list of entries in pid order, of the currently selected entry.
+    selected: usize,
+    /// Index of the first entry currently scrolled into view.
+    scroll_offset: usize,
+    /// How many entries fit on screen as of the last draw, used to size a "page" for
+    /// `PageUp`/`PageDown`.
+    visible_count: usize,
+    /// Whether the previous key pressed in [`Mode::Normal`] was `d`, to recognize the vim-style
+    /// `dd` kill shortcut.
+    pending_delete: bool,
 }
 
 impl Application {
-    pub fn new(config: Config) -> Self {
-        // Set up the system monitoring.
-        let refreshes_kind =
-            ProcessRefreshKind::nothing().with_memory().with_cpu().with_disk_usage();
-        let refreshes = RefreshKind::nothing().with_processes(refreshes_kind);
-        let sys = sysinfo::System::new_with_specifics(refreshes);
-
-        Self { config, is_running: false, sys, refreshes, entries: BTreeMap::new() }
+    pub fn new(config: Config) -> anyhow::Result<Self> {
+        let target = if config.match_path {
+            MatchTarget::Path
+        } else if config.match_cmd {
+            MatchTarget::Cmd
+        } else {
+            MatchTarget::Name
+        };
+        let matcher = Matcher::new(config.name.clone(), target, config.regex)?;
+        let (updates, commands) = collector::spawn(matcher, SAMPLE_INTERVAL);
+
+        Ok(Self {
+            basic: config.basic,
+            config,
+            is_running: false,
+            mode: Mode::Normal,
+            updates,
+            commands,
+            entries: BTreeMap::new(),
+            selected: 0,
+            scroll_offset: 0,
+            visible_count: 1,
+            pending_delete: false,
+        })
     }
 
     pub fn start(&mut self) -> anyhow::Result<()> {
@@ -63,39 +117,44 @@ impl Application {
     }
 
     fn process_frame<B: Backend>(&mut self, terminal: &mut Terminal<B>) -> anyhow::Result<()> {
-        self.sys.refresh_specifics(self.refreshes);
+        self.apply_updates();
+
+        // FIXME: For now I'm panicking here on fail, as I do not like the need for
+        // where B::Error: Error + Sync + Send + 'static
+        // in the method signature.
+        terminal.draw(|frame| self.draw(frame)).expect("failed to draw frame");
+
+        Ok(())
+    }
+
+    /// Drain whatever snapshots the collector thread has sent since the last frame and fold
+    /// them into `entries`. This is the only place samples are appended, so the sample rate is
+    /// entirely governed by the collector's own tick, not by how often we happen to redraw.
+    fn apply_updates(&mut self) {
+        while let Ok(update) = self.updates.try_recv() {
+            match update {
+                Update::Snapshot(samples) => self.ingest_snapshot(samples),
+            }
+        }
+    }
+
+    fn ingest_snapshot(&mut self, samples: Vec<collector::ProcessSample>) {
+        let now = Local::now().naive_local();
+        let retention = self.config.retention;
 
-        // TODO: Currently not loving the way we find processes (also want to do it by program
-        // arguments, path, etc). Also, the way we determine whether a process is dead is a bit
-        // weird in my opinion.
-        let processes = self.sys.processes_by_name(self.config.name.as_ref());
         let mut alive = Vec::new();
-        for process in processes {
-            // Add new information to the entry.
-            let pid = process.pid().as_u32();
+        for sample in samples {
+            let pid = sample.pid;
 
             // For a new process, we first create a new entry.
             // If we already know this process, return its entry.
-            let entry = self.entries.entry(pid).or_insert_with(|| Entry {
-                state: EntryState::Alive,
-                name: process.name().to_string_lossy().to_string(),
-                // TODO: Reconsider, bit weird but it works for what we want to do.
-                query: self.config.name.clone(),
-                pid: process.pid().as_u32(),
-                // TODO: The time stuff is a bit hastily implemented. Sit with it for a second.
-                start: Local::now().naive_local() - Duration::from_secs(process.run_time()),
-                // These we will fill in very shortly.
-                mem: Default::default(),
-                cpu: Default::default(),
-                read: Default::default(),
-                write: Default::default(),
-                layout: EntryLayout::Expanded,
+            let entry = self.entries.entry(pid).or_insert_with(|| {
+                Entry::new(pid, sample.matched.clone(), sample.match_span.clone(), sample.run_time, retention)
             });
 
-            entry.mem.push(Size::from_bytes(process.memory()));
-            entry.cpu.push(process.cpu_usage() / 100.0);
-            entry.read.push(Size::from_bytes(process.disk_usage().total_read_bytes));
-            entry.write.push(Size::from_bytes(process.disk_usage().total_written_bytes));
+            entry.mem.push(now, Size::from_bytes(sample.memory));
+            entry.cpu.push(now, sample.cpu_usage / 100.0);
+            entry.push_disk_usage(now, sample.total_read_bytes, sample.total_written_bytes);
             alive.push(pid);
         }
 
@@ -105,65 +164,172 @@ impl Application {
                 entry.die()
             }
         });
+    }
 
-        // FIXME: For now I'm panicking here on fail, as I do not like the need for
-        // where B::Error: Error + Sync + Send + 'static
-        // in the method signature.
-        terminal.draw(|frame| self.draw(frame)).expect("failed to draw frame");
+    fn draw(&mut self, frame: &mut Frame) {
+        let prompt_height = if matches!(self.mode, Mode::ConfirmKill { .. }) { 1 } else { 0 };
+        let [entries_area, prompt_area] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(prompt_height)])
+                .areas(frame.area());
 
-        Ok(())
+        if self.basic {
+            self.draw_basic_rows(frame, entries_area);
+        } else {
+            self.draw_entries(frame, entries_area);
+        }
+
+        if let Mode::ConfirmKill { pid } = self.mode {
+            let name = self.entries.get(&pid).map(|e| e.matched.as_str()).unwrap_or_default();
+            let prompt = Paragraph::new(Line::from(vec![
+                Span::raw(format!("Kill {name} (pid {pid})? ")).bold(),
+                Span::raw("[y/n]").dim(),
+            ]));
+            frame.render_widget(prompt, prompt_area);
+        }
     }
 
-    fn draw(&mut self, frame: &mut Frame) {
-        let entry_heights = self.entries.values().map(|e| 1 + e.layout.chart_height());
+    fn draw_entries(&mut self, frame: &mut Frame, area: ratatui::layout::Rect) {
+        let len = self.entries.len();
+        if len == 0 {
+            self.selected = 0;
+            self.scroll_offset = 0;
+            return;
+        }
+        self.selected = self.selected.min(len - 1);
+
+        let heights: Vec<u16> = self.entries.values().map(|e| 1 + e.layout.chart_height()).collect();
+
+        // Keep the selection within the visible window: snap the window's start up to the
+        // selection if it scrolled above it, or push the start down until the selection fits
+        // again if it scrolled below it.
+        self.scroll_offset = self.scroll_offset.min(self.selected);
+        while self.scroll_offset < self.selected
+            && heights[self.scroll_offset..=self.selected].iter().sum::<u16>() > area.height
+        {
+            self.scroll_offset += 1;
+        }
 
         let n_visible_entries = {
             let mut n = 0;
             let mut total_height = 0;
-            for h in entry_heights.clone() {
+            for &h in &heights[self.scroll_offset..] {
                 total_height += h;
-                if total_height > frame.area().height {
+                if total_height > area.height {
                     break;
                 }
                 n += 1;
             }
-            n
+            n.max(1)
         };
+        self.visible_count = n_visible_entries;
+
+        let end = (self.scroll_offset + n_visible_entries).min(len);
         let vertical =
-            Layout::vertical(entry_heights.take(n_visible_entries).map(|h| Constraint::Length(h)));
-        let rows = vertical.split(frame.area());
-        for (&row, entry) in rows.into_iter().zip(self.entries.values()) {
-            frame.render_widget(entry, row);
+            Layout::vertical(heights[self.scroll_offset..end].iter().map(|&h| Constraint::Length(h)));
+        let rows = vertical.split(area);
+        let visible_entries = self.entries.values().skip(self.scroll_offset);
+        for (i, (&row, entry)) in rows.into_iter().zip(visible_entries).enumerate() {
+            let selected = self.scroll_offset + i == self.selected;
+            frame.render_widget(EntryWidget { entry, selected }, row);
+        }
+    }
+
+    /// Like `draw_entries`, but for `--basic` mode: every row is exactly one line tall, since
+    /// there are no charts to size around.
+    fn draw_basic_rows(&mut self, frame: &mut Frame, area: ratatui::layout::Rect) {
+        let len = self.entries.len();
+        if len == 0 {
+            self.selected = 0;
+            self.scroll_offset = 0;
+            return;
+        }
+        self.selected = self.selected.min(len - 1);
+
+        let visible_count = (area.height as usize).max(1);
+        self.scroll_offset = self.scroll_offset.min(self.selected);
+        if self.selected >= self.scroll_offset + visible_count {
+            self.scroll_offset = self.selected + 1 - visible_count;
+        }
+        self.visible_count = visible_count;
+
+        let n = visible_count.min(len - self.scroll_offset);
+        let vertical = Layout::vertical(vec![Constraint::Length(1); n]);
+        let rows = vertical.split(area);
+        let visible_entries = self.entries.values().skip(self.scroll_offset);
+        for (i, (&row, entry)) in rows.into_iter().zip(visible_entries).enumerate() {
+            let selected = self.scroll_offset + i == self.selected;
+            frame.render_widget(BasicRowWidget { entry, selected }, row);
         }
     }
 
     fn handle_events(&mut self) -> anyhow::Result<()> {
-        // TODO: I hate this so much. The input handling is very poor here.
-        // Also: known issue, when you just press a bunch of buttons, the updates will
-        // happen more frequently. That is a problem for the CPU sampling, actually.
-        // Ultimately, I want a thread that does all of the system monitoring for me.
-        if event::poll(Duration::from_millis(200)).context("failed to poll event")? {
-            match event::read().context("failed to read event")? {
-                Event::Key(ke)
-                    if ke.code == KeyCode::Char('q')
-                        || (ke.code == KeyCode::Char('c')
-                            && ke.modifiers.contains(KeyModifiers::CONTROL)) =>
-                {
-                    self.stop();
-                }
-                Event::Key(ke) if ke.code == KeyCode::Char('r') => {
-                    self.entries.clear();
-                }
-                Event::Key(ke) if ke.code == KeyCode::Char('E') => {
-                    self.entries.values_mut().for_each(|e| e.layout = EntryLayout::Expanded);
-                }
-                Event::Key(ke) if ke.code == KeyCode::Char('C') => {
-                    self.entries.values_mut().for_each(|e| e.layout = EntryLayout::Condensed);
-                }
-                _ => {}
+        if event::poll(INPUT_POLL_INTERVAL).context("failed to poll event")? {
+            let event = event::read().context("failed to read event")?;
+            match self.mode {
+                Mode::Normal => self.handle_normal_event(event),
+                Mode::ConfirmKill { pid } => self.handle_confirm_kill_event(event, pid),
             }
         }
 
         Ok(())
     }
+
+    fn handle_normal_event(&mut self, event: Event) {
+        let Event::Key(ke) = event else { return };
+
+        // Recognize `dd`, vim-style, as a second way to kill the selected process.
+        let is_d = ke.code == KeyCode::Char('d');
+        let triggered_dd = self.pending_delete && is_d;
+        self.pending_delete = is_d && !self.pending_delete;
+
+        match ke.code {
+            KeyCode::Char('q') => self.stop(),
+            KeyCode::Char('c') if ke.modifiers.contains(KeyModifiers::CONTROL) => self.stop(),
+            KeyCode::Char('r') => self.entries.clear(),
+            KeyCode::Char('E') => {
+                self.entries.values_mut().for_each(|e| e.layout = EntryLayout::Expanded)
+            }
+            KeyCode::Char('C') => {
+                self.entries.values_mut().for_each(|e| e.layout = EntryLayout::Condensed)
+            }
+            KeyCode::Char('B') => self.basic = !self.basic,
+            KeyCode::Char('j') | KeyCode::Down => self.move_selection(1),
+            KeyCode::Char('k') | KeyCode::Up => self.move_selection(-1),
+            KeyCode::PageDown => self.move_selection(self.visible_count as isize),
+            KeyCode::PageUp => self.move_selection(-(self.visible_count as isize)),
+            KeyCode::Char('K') => self.request_kill(),
+            _ if triggered_dd => self.request_kill(),
+            _ => {}
+        }
+    }
+
+    fn handle_confirm_kill_event(&mut self, event: Event, pid: u32) {
+        let Event::Key(ke) = event else { return };
+
+        match ke.code {
+            KeyCode::Char('y') | KeyCode::Enter => {
+                // If sending fails, the collector thread is gone, so there is nothing more we
+                // could do about it anyway.
+                let _ = self.commands.send(Command::Kill(pid));
+                self.mode = Mode::Normal;
+            }
+            KeyCode::Char('n') | KeyCode::Esc => self.mode = Mode::Normal,
+            _ => {}
+        }
+    }
+
+    fn move_selection(&mut self, delta: isize) {
+        let len = self.entries.len();
+        if len == 0 {
+            return;
+        }
+        self.selected = (self.selected as isize + delta).clamp(0, len as isize - 1) as usize;
+    }
+
+    /// Ask for confirmation before killing the currently selected process.
+    fn request_kill(&mut self) {
+        if let Some(&pid) = self.entries.keys().nth(self.selected) {
+            self.mode = Mode::ConfirmKill { pid };
+        }
+    }
 }
@@ -1,27 +1,69 @@
+use std::time::Duration;
+
 use clap::Parser;
 
 use crate::app::Application;
 
 mod app;
-mod draw;
-mod entry;
 
 /// Observe memory, cpu, and disk I/O for processes matching the provided name.
 ///
 /// To clear and reset all entries, press `r`. Use `C` and `E` to collapse and expand all entries,
 /// respectively. Exit with `^C` or `q`.
 ///
+/// Navigate entries with `j`/`k` or the arrow keys, and page through them with `PageUp`/
+/// `PageDown`. Kill the selected process with `K` or `dd`, then confirm with `y` (or cancel
+/// with `n`/`Esc`). Toggle the compact `--basic` table with `B`.
+///
 /// By Marieke Westendorp, 2025, <ma3ke.cyber@gmail.com>.
 #[derive(Parser)]
 #[clap(version)]
 struct Config {
     /// Name of the program to watch.
     name: String,
+
+    /// How far back in time to keep samples for each process, e.g. `30s`, `10m`, `2h`.
+    /// Older samples are evicted as new ones come in.
+    #[clap(long, default_value = "10m", value_parser = parse_duration)]
+    retention: Duration,
+
+    /// Match against the process's full executable path, instead of its name.
+    #[clap(long, conflicts_with = "match_cmd")]
+    match_path: bool,
+
+    /// Match against the process's command-line arguments, instead of its name.
+    #[clap(long, conflicts_with = "match_path")]
+    match_cmd: bool,
+
+    /// Treat `name` as a regular expression, instead of a plain substring.
+    #[clap(long)]
+    regex: bool,
+
+    /// Render a compact one-line-per-process table instead of charts. Useful for narrow
+    /// terminals or slow SSH links. Can also be toggled at runtime with `B`.
+    #[clap(long)]
+    basic: bool,
+}
+
+/// Parse a duration given as a number followed by a `s`, `m`, `h`, or `d` suffix (seconds,
+/// minutes, hours, days). A bare number is interpreted as seconds.
+fn parse_duration(s: &str) -> Result<Duration, String> {
+    let split = s.find(|c: char| c.is_alphabetic()).unwrap_or(s.len());
+    let (value, unit) = s.split_at(split);
+    let value: u64 = value.parse().map_err(|_| format!("invalid duration `{s}`"))?;
+    let secs = match unit {
+        "" | "s" => value,
+        "m" => value * 60,
+        "h" => value * 60 * 60,
+        "d" => value * 60 * 60 * 24,
+        _ => return Err(format!("unknown duration unit `{unit}`, expected one of s, m, h, d")),
+    };
+    Ok(Duration::from_secs(secs))
 }
 
 fn main() -> anyhow::Result<()> {
     let config = Config::parse();
-    let mut app = Application::new(config);
+    let mut app = Application::new(config)?;
     app.start()?;
     Ok(())
 }